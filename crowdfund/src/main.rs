@@ -8,34 +8,71 @@ pub struct Crowdfunding;
 pub struct Campaign {
     creator: Address,
     goal: BigInt,
+    start_time: u64,
     deadline: u64,
     contributions: Map<Address, BigInt>,
     total_contributed: BigInt,
     status: CampaignStatus,
     token: Option<Token>, // Optional token for campaign (if using an ERC-20 token)
+    contributions_root: Option<BytesN<32>>, // Merkle root of contributions, set once the goal is reached and again at finalize time
+    min_contribution: BigInt, // Smallest amount accepted by `contribute`, rejects dust pledges
+    submission_deposit: BigInt, // Creator's deposit; returned on withdraw, forfeited on failure
 }
 
 #[derive(Clone)]
 pub enum CampaignStatus {
+    Pending,
     Active,
     Successful,
     Failed,
     Expired,
+    Canceled,
+    Withdrawn,
 }
 
 #[contractimpl]
 impl Crowdfunding {
-    // Create a new campaign
-    pub fn create_campaign(env: Env, goal: BigInt, deadline: u64, token: Option<Token>) -> Campaign {
+    // Create a new campaign. If `start_time` is in the future the campaign opens
+    // as Pending and only starts accepting contributions once it is reached.
+    // The creator's `submission_deposit` is collected immediately, giving them
+    // skin in the game: it's returned on a successful withdraw, forfeited on failure.
+    pub fn create_campaign(
+        env: Env,
+        goal: BigInt,
+        start_time: u64,
+        deadline: u64,
+        token: Option<Token>,
+        min_contribution: BigInt,
+        submission_deposit: BigInt,
+    ) -> Campaign {
         let creator = env.invoker();
+        let status = if start_time > env.timestamp() {
+            CampaignStatus::Pending
+        } else {
+            CampaignStatus::Active
+        };
+
+        // Collect the creator's submission deposit (use token if available, else native currency)
+        if submission_deposit > BigInt::zero() {
+            if let Some(token) = &token {
+                token.transfer_to_contract(&creator, submission_deposit.clone());
+            } else {
+                env.transfer_to_contract(&creator, submission_deposit.clone());
+            }
+        }
+
         Campaign {
             creator,
             goal,
+            start_time,
             deadline,
             contributions: Map::new(&env),
             total_contributed: BigInt::zero(),
-            status: CampaignStatus::Active,
+            status,
             token,
+            contributions_root: None,
+            min_contribution,
+            submission_deposit,
         }
     }
 
@@ -56,8 +93,10 @@ impl Crowdfunding {
 
     // Contribute to the campaign
     pub fn contribute(env: Env, campaign: &mut Campaign, amount: BigInt) {
+        assert!(env.timestamp() >= campaign.start_time, "Campaign has not started yet");
         assert!(env.timestamp() < campaign.deadline, "Campaign has ended");
         assert!(amount > BigInt::zero(), "Contribution must be positive");
+        assert!(amount >= campaign.min_contribution, "Contribution is below the minimum");
 
         let contributor = env.invoker();
         let existing_contribution = campaign.contributions.get(&contributor).unwrap_or(BigInt::zero());
@@ -67,14 +106,49 @@ impl Crowdfunding {
         campaign.total_contributed += amount;
 
         // Emit ContributionReceived event
-        Self::emit_contribution_received(env, contributor, amount);
+        Self::emit_contribution_received(env.clone(), contributor, amount);
 
         // Check if the campaign goal is met
         if campaign.total_contributed >= campaign.goal {
             campaign.status = CampaignStatus::Successful;
+            // Record the contributions root whenever a contribution leaves the total at or
+            // past the goal, so a campaign withdrawn without ever calling finalize_campaign
+            // still has something for the off-chain reward flow to verify against.
+            campaign.contributions_root = Some(Self::contributions_root(env.clone(), campaign));
         }
     }
 
+    // Event: Pledge withdrawn
+    pub fn emit_pledge_withdrawn(env: Env, contributor: Address, amount: BigInt) {
+        env.emit_event(symbol!("PledgeWithdrawn"), &contributor, &amount);
+    }
+
+    // Withdraw part or all of your own pledge while the campaign is still active.
+    // Once the goal has been reached, pledges are locked and only the creator can claim them.
+    pub fn unpledge(env: Env, campaign: &mut Campaign, amount: BigInt) {
+        assert!(campaign.total_contributed < campaign.goal, "Funds are locked: goal has been reached");
+        assert!(Self::check_status(env.clone(), campaign) == CampaignStatus::Active, "Campaign is not active");
+        assert!(env.timestamp() < campaign.deadline, "Campaign has ended");
+        assert!(amount > BigInt::zero(), "Unpledge amount must be positive");
+
+        let contributor = env.invoker();
+        let existing_contribution = campaign.contributions.get(&contributor).unwrap_or(BigInt::zero());
+        assert!(existing_contribution >= amount, "Cannot unpledge more than you contributed");
+
+        campaign.contributions.insert(&contributor, existing_contribution - amount.clone());
+        campaign.total_contributed -= amount.clone();
+
+        // Return the funds to the contributor (use token if available, else native currency)
+        if let Some(token) = &campaign.token {
+            token.transfer_from_contract(&contributor, amount.clone());
+        } else {
+            env.transfer_from_contract(&contributor, amount.clone());
+        }
+
+        // Emit PledgeWithdrawn event
+        Self::emit_pledge_withdrawn(env, contributor, amount);
+    }
+
     // Withdraw funds by the creator (if campaign is successful)
     pub fn withdraw(env: Env, campaign: &mut Campaign) {
         let creator = env.invoker();
@@ -84,13 +158,21 @@ impl Crowdfunding {
         let amount_to_withdraw = campaign.total_contributed.clone();
         campaign.total_contributed = BigInt::zero();
 
+        // The submission deposit is returned alongside the raised funds
+        let deposit_to_return = campaign.submission_deposit.clone();
+        campaign.submission_deposit = BigInt::zero();
+        let total_to_creator = amount_to_withdraw.clone() + deposit_to_return;
+
         // Transfer the funds to the creator (use token if available, else native currency)
         if let Some(token) = &campaign.token {
-            token.transfer_from_contract(&creator, amount_to_withdraw.clone());
+            token.transfer_from_contract(&creator, total_to_creator.clone());
         } else {
-            env.transfer_from_contract(&creator, amount_to_withdraw.clone());
+            env.transfer_from_contract(&creator, total_to_creator.clone());
         }
 
+        // Mark the campaign as withdrawn so it can no longer be canceled or re-withdrawn
+        campaign.status = CampaignStatus::Withdrawn;
+
         // Emit FundsWithdrawn event
         Self::emit_funds_withdrawn(env, creator, amount_to_withdraw);
     }
@@ -117,28 +199,233 @@ impl Crowdfunding {
         Self::emit_refund_issued(env, contributor, amount_contributed);
     }
 
-    // Check the current status of the campaign
+    // Event: Campaign canceled by creator
+    pub fn emit_campaign_canceled(env: Env, creator: Address, reason: Bytes) {
+        env.emit_event(symbol!("CampaignCanceled"), &creator, &reason);
+    }
+
+    // Cancel the campaign. Only the creator can cancel, and only before the campaign
+    // has been withdrawn. This just marks the campaign Canceled; it does not refund
+    // contributors itself — call `refund_batch` afterward to drain the contributions
+    // map in bounded chunks, so a large campaign's teardown can't exceed resource limits.
+    pub fn cancel_campaign(env: Env, campaign: &mut Campaign, reason: Bytes) {
+        let creator = env.invoker();
+        assert!(creator == campaign.creator, "Only the creator can cancel");
+        assert!(campaign.status != CampaignStatus::Canceled, "Campaign already canceled");
+        assert!(campaign.status != CampaignStatus::Withdrawn, "Cannot cancel after funds have been withdrawn");
+
+        campaign.status = CampaignStatus::Canceled;
+
+        // This is a voluntary cancellation, so the creator's submission deposit is
+        // returned rather than forfeited (use token if available, else native currency)
+        let deposit_to_return = campaign.submission_deposit.clone();
+        campaign.submission_deposit = BigInt::zero();
+        if deposit_to_return > BigInt::zero() {
+            if let Some(token) = &campaign.token {
+                token.transfer_from_contract(&creator, deposit_to_return.clone());
+            } else {
+                env.transfer_from_contract(&creator, deposit_to_return.clone());
+            }
+        }
+
+        // Emit CampaignCanceled event
+        Self::emit_campaign_canceled(env, creator, reason);
+    }
+
+    // Refund at most `limit` contributors, removing each processed entry from the
+    // contributions map. Returns the number of entries processed (an entry with a zero
+    // balance is removed and counted without a transfer), so callers can drive
+    // teardown of a large campaign across multiple transactions.
+    pub fn refund_batch(env: Env, campaign: &mut Campaign, limit: u32) -> u32 {
+        assert!(
+            campaign.status == CampaignStatus::Failed || campaign.status == CampaignStatus::Canceled,
+            "Campaign must be failed or canceled to batch refund"
+        );
+
+        let keys = campaign.contributions.keys();
+        let mut processed: u32 = 0;
+
+        for contributor in keys.iter() {
+            if processed >= limit {
+                break;
+            }
+
+            let amount = campaign.contributions.get(&contributor).unwrap_or(BigInt::zero());
+            if amount > BigInt::zero() {
+                if let Some(token) = &campaign.token {
+                    token.transfer_from_contract(&contributor, amount.clone());
+                } else {
+                    env.transfer_from_contract(&contributor, amount.clone());
+                }
+                campaign.total_contributed -= amount.clone();
+
+                // Emit RefundIssued event
+                Self::emit_refund_issued(env.clone(), contributor.clone(), amount);
+            }
+
+            campaign.contributions.remove(&contributor);
+            processed += 1;
+        }
+
+        processed
+    }
+
+    // Whether every contributor has been refunded (the contributions map is fully drained).
+    // `withdraw` does not consult it; it only runs on a Successful campaign, which
+    // `refund_batch` never processes.
+    pub fn fully_refunded(campaign: &Campaign) -> bool {
+        campaign.contributions.is_empty()
+    }
+
+    // Returns the campaign's core configuration: goal, start time, deadline, status and token
+    pub fn get_config(env: Env, campaign: &Campaign) -> (BigInt, u64, u64, CampaignStatus, Option<Token>) {
+        (campaign.goal.clone(), campaign.start_time, campaign.deadline, campaign.status.clone(), campaign.token.clone())
+    }
+
+    // Returns the total amount contributed so far
+    pub fn get_funds(env: Env, campaign: &Campaign) -> BigInt {
+        campaign.total_contributed.clone()
+    }
+
+    // Returns the amount a given contributor has pledged
+    pub fn get_shares(env: Env, campaign: &Campaign, contributor: Address) -> BigInt {
+        campaign.contributions.get(&contributor).unwrap_or(BigInt::zero())
+    }
+
+    // Returns every contributor and their pledged amount
+    pub fn get_funders(env: Env, campaign: &Campaign) -> Vec<(Address, BigInt)> {
+        let mut funders = Vec::new(&env);
+        for (contributor, amount) in campaign.contributions.iter() {
+            funders.push_back((contributor, amount));
+        }
+        funders
+    }
+
+    // Hashes a single (Address, BigInt) pledge into a Merkle leaf: sha256(address || amount_be)
+    fn merkle_leaf(env: &Env, address: &Address, amount: &BigInt) -> BytesN<32> {
+        let mut data = Bytes::new(env);
+        data.append(&address.to_xdr(env));
+        data.append(&amount.to_be_bytes(env));
+        env.crypto().sha256(&data)
+    }
+
+    // Hashes two sibling nodes together to form their parent: sha256(left || right)
+    fn merkle_parent(env: &Env, left: &BytesN<32>, right: &BytesN<32>) -> BytesN<32> {
+        let mut data = Bytes::new(env);
+        data.append(&Bytes::from(left.clone()));
+        data.append(&Bytes::from(right.clone()));
+        env.crypto().sha256(&data)
+    }
+
+    // Returns a Merkle root committing to every (Address, BigInt) pledge, built in
+    // address-sorted order so it can be reproduced off-chain. An external reward
+    // contract can verify a (address, amount, proof) claim by re-walking the proof
+    // hashes up to this root.
+    pub fn contributions_root(env: Env, campaign: &Campaign) -> BytesN<32> {
+        if campaign.contributions.is_empty() {
+            return BytesN::from_array(&env, &[0u8; 32]);
+        }
+
+        let mut pairs: Vec<(Address, BigInt)> = Vec::new(&env);
+        for (contributor, amount) in campaign.contributions.iter() {
+            pairs.push_back((contributor, amount));
+        }
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut level: Vec<BytesN<32>> = Vec::new(&env);
+        for (contributor, amount) in pairs.iter() {
+            level.push_back(Self::merkle_leaf(&env, contributor, amount));
+        }
+
+        while level.len() > 1 {
+            let mut next_level: Vec<BytesN<32>> = Vec::new(&env);
+            let mut i = 0;
+            while i < level.len() {
+                if i + 1 < level.len() {
+                    next_level.push_back(Self::merkle_parent(&env, &level.get(i).unwrap(), &level.get(i + 1).unwrap()));
+                } else {
+                    // Odd node out: promote it unchanged to the next level
+                    next_level.push_back(level.get(i).unwrap());
+                }
+                i += 2;
+            }
+            level = next_level;
+        }
+
+        level.get(0).unwrap()
+    }
+
+    // Reports what the campaign's status is, without committing it. This is a pure
+    // read: only `finalize_campaign` settles a terminal state, since that's also the
+    // single point that forfeits the submission deposit and freezes the contributions
+    // root — letting this method write the same transition too would let whichever
+    // of the two runs first decide the campaign's economics non-deterministically.
     pub fn check_status(env: Env, campaign: &Campaign) -> CampaignStatus {
-        // If the deadline has passed, mark the campaign as expired if not successful
+        if env.timestamp() < campaign.start_time {
+            return CampaignStatus::Pending;
+        }
+
         if env.timestamp() > campaign.deadline {
-            if campaign.total_contributed >= campaign.goal {
-                campaign.status = CampaignStatus::Successful;
+            return if campaign.total_contributed >= campaign.goal {
+                CampaignStatus::Successful
             } else {
-                campaign.status = CampaignStatus::Failed;
-            }
+                CampaignStatus::Failed
+            };
+        }
+
+        if matches!(campaign.status, CampaignStatus::Pending) {
+            return CampaignStatus::Active;
         }
 
         campaign.status.clone()
     }
 
+    // Forfeit the creator's submission deposit into the refund pool when the campaign
+    // fails, splitting it evenly across existing contributors (remainder to the first).
+    // With no contributors to share it with, the deposit is simply burned.
+    fn forfeit_deposit(campaign: &mut Campaign) {
+        let deposit = campaign.submission_deposit.clone();
+        if deposit <= BigInt::zero() {
+            return;
+        }
+        campaign.submission_deposit = BigInt::zero();
+
+        let keys = campaign.contributions.keys();
+        if keys.is_empty() {
+            return;
+        }
+
+        let count = BigInt::from(keys.len());
+        let share = deposit.clone() / count.clone();
+        let remainder = deposit - share.clone() * count;
+
+        let mut first = true;
+        for contributor in keys.iter() {
+            let mut portion = share.clone();
+            if first {
+                portion += remainder.clone();
+                first = false;
+            }
+
+            let existing = campaign.contributions.get(contributor).unwrap_or(BigInt::zero());
+            campaign.contributions.insert(contributor, existing + portion.clone());
+            campaign.total_contributed += portion;
+        }
+    }
+
     // Finalize the campaign if the deadline has passed
     pub fn finalize_campaign(env: Env, campaign: &mut Campaign) {
-        if env.timestamp() > campaign.deadline {
+        if env.timestamp() < campaign.start_time {
+            campaign.status = CampaignStatus::Pending;
+        } else if env.timestamp() > campaign.deadline {
             if campaign.total_contributed >= campaign.goal {
                 campaign.status = CampaignStatus::Successful;
             } else {
                 campaign.status = CampaignStatus::Failed;
+                Self::forfeit_deposit(campaign);
             }
+            // Freeze the contributions root once the campaign's outcome is settled
+            campaign.contributions_root = Some(Self::contributions_root(env.clone(), campaign));
         } else {
             campaign.status = CampaignStatus::Expired;
         }